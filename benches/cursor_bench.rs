@@ -1,6 +1,9 @@
 use bytes::{BufMut, BytesMut};
 use criterion::{black_box, criterion_group, criterion_main, profiler::Profiler, Criterion};
-use rust_cursor_bench::{MyStruct, WriterVec, serialize_it};
+use rust_cursor_bench::{
+    MyStruct, WriterVec, FixedBuffer, WriteWith, BitBuffer, serialize_it, serialize_infallible,
+    encode_my_struct, serialize_vectored, serialize_bits, ENCODED_SIZE,
+};
 use pprof::ProfilerGuard;
 use std::{fs::File, io::Cursor, path::Path};
 
@@ -42,10 +45,13 @@ impl<'a> Profiler for FlamegraphProfiler<'a> {
     }
 }
 
-const ENCODED_SIZE: usize = 16;
 const NUM_CHUNKS: usize = 2048;
 const BUFFER_SIZE: usize = NUM_CHUNKS * ENCODED_SIZE;
 
+// Staging capacity for `do_fixed_buffer`: big enough to hold several
+// `MyStruct`s so we can see the staging buffer fill and flush a few times.
+const FIXED_BUF_CAP: usize = ENCODED_SIZE * 64;
+
 fn do_cursor_vec() {
     let mut c = Cursor::new(Vec::<u8>::with_capacity(BUFFER_SIZE));
 
@@ -139,6 +145,84 @@ fn do_writervec() {
     assert_eq!(s, 24);
 }
 
+fn do_writervec_infallible() {
+    let mut b = WriterVec::with_capacity(BUFFER_SIZE);
+
+    for ii in 0..NUM_CHUNKS {
+        // Conceal the source of the data, to avoid optimizing it away
+        let test_struct = black_box(MyStruct::new(ii));
+        serialize_infallible(&test_struct, &mut b);
+    }
+
+    let v = b.into_inner();
+    assert_eq!(v.len(), BUFFER_SIZE);
+
+    let s: u8 = v.iter().sum();
+    assert_eq!(s, 24);
+}
+
+fn do_fixed_buffer() {
+    let mut b = FixedBuffer::<_, FIXED_BUF_CAP>::new(Vec::<u8>::with_capacity(BUFFER_SIZE));
+
+    for ii in 0..NUM_CHUNKS {
+        // Conceal the source of the data, to avoid optimizing it away
+        let test_struct = black_box(MyStruct::new(ii));
+        serialize_it(&test_struct, &mut b);
+    }
+
+    let v = b.into_inner();
+    assert_eq!(v.len(), BUFFER_SIZE);
+
+    let s: u8 = v.iter().sum();
+    assert_eq!(s, 24);
+}
+
+fn do_writervec_write_with() {
+    let mut b = WriterVec::with_capacity(BUFFER_SIZE);
+
+    for ii in 0..NUM_CHUNKS {
+        // Conceal the source of the data, to avoid optimizing it away
+        let test_struct = black_box(MyStruct::new(ii));
+        b.write_with(|buf| encode_my_struct(&test_struct, buf));
+    }
+
+    let v = b.into_inner();
+    assert_eq!(v.len(), BUFFER_SIZE);
+
+    let s: u8 = v.iter().sum();
+    assert_eq!(s, 24);
+}
+
+fn do_writervec_vectored() {
+    let mut b = WriterVec::with_capacity(BUFFER_SIZE);
+
+    for ii in 0..NUM_CHUNKS {
+        // Conceal the source of the data, to avoid optimizing it away
+        let test_struct = black_box(MyStruct::new(ii));
+        serialize_vectored(&test_struct, &mut b);
+    }
+
+    let v = b.into_inner();
+    assert_eq!(v.len(), BUFFER_SIZE);
+
+    let s: u8 = v.iter().sum();
+    assert_eq!(s, 24);
+}
+
+fn do_bitbuffer() {
+    let mut bits = BitBuffer::with_capacity(BUFFER_SIZE);
+
+    for ii in 0..NUM_CHUNKS {
+        // Conceal the source of the data, to avoid optimizing it away
+        let test_struct = black_box(MyStruct::new(ii));
+        serialize_bits(&test_struct, &mut bits);
+    }
+
+    let v = bits.into_inner();
+    // Each MyStruct packs into 64 + 32 + 8 + 1 + 16 = 121 bits.
+    assert_eq!(v.len(), NUM_CHUNKS * 121 / 8);
+}
+
 fn do_array() {
     let mut b = [0; BUFFER_SIZE];
     let mut cursor = &mut b[..];
@@ -161,6 +245,11 @@ fn bench_cursors(c: &mut Criterion) {
     group.bench_function("Cursor<Vec<u8>>", |b| b.iter(do_cursor_vec));
     group.bench_function("Cursor<&mut Vec<u8>>", |b| b.iter(do_cursor_vec_ref));
     group.bench_function("WriterVec", |b| b.iter(do_writervec));
+    group.bench_function("WriterVec (infallible)", |b| b.iter(do_writervec_infallible));
+    group.bench_function("FixedBuffer", |b| b.iter(do_fixed_buffer));
+    group.bench_function("WriterVec (write_with)", |b| b.iter(do_writervec_write_with));
+    group.bench_function("WriterVec (vectored)", |b| b.iter(do_writervec_vectored));
+    group.bench_function("BitBuffer", |b| b.iter(do_bitbuffer));
     group.bench_function("BytesMut", |b| b.iter(do_bytesmut));
     group.bench_function("Cursor<&mut [u8]>", |b| b.iter(do_cursor_slice));
     group.bench_function("Cursor<Box<[u8]>>", |b| b.iter(do_cursor_box));
@@ -1,7 +1,7 @@
 use bincode::Options;
 use bytes::{BufMut, BytesMut};
 use serde::Serialize;
-use std::io::{Write, Cursor};
+use std::io::{IoSlice, Write, Cursor};
 
 pub fn be_coder() -> impl Options {
     bincode::DefaultOptions::new()
@@ -10,6 +10,9 @@ pub fn be_coder() -> impl Options {
         .allow_trailing_bytes()
 }
 
+/// Number of bytes `be_coder` produces when serializing one `MyStruct`.
+pub const ENCODED_SIZE: usize = 16;
+
 #[derive(Debug, Default, Serialize)]
 pub struct MyStruct {
     a: u64,
@@ -82,4 +85,245 @@ impl Write for WriterVec {
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+        let total: usize = bufs.iter().map(|buf| buf.len()).sum();
+        self.0.reserve(total);
+        for buf in bufs {
+            self.0.extend_from_slice(buf);
+        }
+        Ok(total)
+    }
+}
+
+impl WriteWith for WriterVec {
+    fn write_with<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut [u8; ENCODED_SIZE]) -> usize,
+    {
+        let start = self.0.len();
+        self.0.resize(start + ENCODED_SIZE, 0);
+        let chunk: &mut [u8; ENCODED_SIZE] = (&mut self.0[start..start + ENCODED_SIZE])
+            .try_into()
+            .unwrap();
+        let written = f(chunk);
+        self.0.truncate(start + written);
+    }
+}
+
+/// A sink that can only ever grow, and so never fails to accept bytes.
+///
+/// `std::io::Write` forces callers (and bincode's encoder) to check a
+/// `Result` after every field, even when the underlying sink is something
+/// like `WriterVec` that can't actually fail. Implementing this trait
+/// instead lets `serialize_infallible` drive bincode through an adapter
+/// whose writes always return `Ok`; bincode's own `Result` plumbing is
+/// still in place, it just never sees an error.
+pub trait AppendOnly {
+    fn append(&mut self, bytes: &[u8]);
+}
+
+impl AppendOnly for Vec<u8> {
+    fn append(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+impl AppendOnly for WriterVec {
+    fn append(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+/// Adapts an `AppendOnly` sink to `std::io::Write`, so it can be driven
+/// through bincode's `Write`-based API, without the adapter itself ever
+/// being able to produce an `Err`.
+struct InfallibleWriter<'a, T>(&'a mut T);
+
+impl<'a, T: AppendOnly> Write for InfallibleWriter<'a, T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.append(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[inline(never)]
+pub fn serialize_infallible<T, W>(item: &T, sink: &mut W)
+where
+    T: serde::Serialize,
+    W: AppendOnly,
+{
+    let mut adapter = InfallibleWriter(sink);
+    be_coder().serialize_into(&mut adapter, &item).unwrap();
+}
+
+/// A `Write` sink that stages bytes in a fixed-size `[u8; N]` buffer before
+/// forwarding them to a backing sink, instead of growing an unbounded
+/// `Vec` the way `WriterVec` does.
+///
+/// The capacity check is amortized: each `write` only has to check whether
+/// the staging buffer has room, and only flushes to `sink` when it doesn't,
+/// rather than reallocating on every call.
+pub struct FixedBuffer<W, const N: usize> {
+    buf: [u8; N],
+    pos: usize,
+    sink: W,
+}
+
+impl<W: Write, const N: usize> FixedBuffer<W, N> {
+    pub fn new(sink: W) -> Self {
+        FixedBuffer {
+            buf: [0; N],
+            pos: 0,
+            sink,
+        }
+    }
+
+    fn flush_buf(&mut self) {
+        if self.pos > 0 {
+            self.sink
+                .write_all(&self.buf[..self.pos])
+                .expect("backing sink write failed");
+            self.pos = 0;
+        }
+    }
+
+    pub fn into_inner(mut self) -> W {
+        self.flush_buf();
+        self.sink
+    }
+}
+
+impl<W: Write, const N: usize> Write for FixedBuffer<W, N> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.pos + buf.len() > N {
+            self.flush_buf();
+        }
+        if buf.len() > N {
+            // Too big to stage; go straight to the backing sink.
+            self.sink.write_all(buf)?;
+        } else {
+            self.buf[self.pos..self.pos + buf.len()].copy_from_slice(buf);
+            self.pos += buf.len();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flush_buf();
+        self.sink.flush()
+    }
+}
+
+/// Serializes directly into reserved buffer space, instead of issuing a
+/// `Write::write` call (and its capacity check) per field.
+///
+/// The sink reserves `ENCODED_SIZE` bytes once and hands the closure a
+/// fixed-size array pointing at that reserved tail. `WriterVec` zero-fills
+/// the tail before the closure sees it; the bytes are not left
+/// uninitialized. The
+/// closure fills in as much of it as it needs and returns how many bytes it
+/// wrote, and the sink advances its length by that amount.
+pub trait WriteWith {
+    fn write_with<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut [u8; ENCODED_SIZE]) -> usize;
+}
+
+/// Encodes a `MyStruct` into `buf` in one shot, matching the field layout
+/// `be_coder` produces, and returns the number of bytes written.
+pub fn encode_my_struct(item: &MyStruct, buf: &mut [u8; ENCODED_SIZE]) -> usize {
+    buf[0..8].copy_from_slice(&item.a.to_be_bytes());
+    buf[8..12].copy_from_slice(&item.b.to_be_bytes());
+    buf[12] = item.c;
+    buf[13] = item.d as u8;
+    buf[14..16].copy_from_slice(&item.e.to_be_bytes());
+    ENCODED_SIZE
+}
+
+/// Serializes a `MyStruct` as a single scatter-gather `write_vectored`
+/// call, instead of one `write` per field.
+pub fn serialize_vectored(item: &MyStruct, sink: &mut WriterVec) {
+    let a = item.a.to_be_bytes();
+    let b = item.b.to_be_bytes();
+    let c = [item.c];
+    let d = [item.d as u8];
+    let e = item.e.to_be_bytes();
+    let slices = [
+        IoSlice::new(&a),
+        IoSlice::new(&b),
+        IoSlice::new(&c),
+        IoSlice::new(&d),
+        IoSlice::new(&e),
+    ];
+    let written = sink.write_vectored(&slices).unwrap();
+    debug_assert_eq!(written, ENCODED_SIZE);
+}
+
+/// A bit-packed sink: an alternative to `be_coder`'s fixint encoding, which
+/// always spends a fixed `ENCODED_SIZE` bytes per `MyStruct` regardless of
+/// the actual field values. `BitBuffer` instead packs fields at bit
+/// granularity into a `u128` accumulator, flushing full bytes to the
+/// backing `Vec` as it fills.
+pub struct BitBuffer {
+    buf: Vec<u8>,
+    acc: u128,
+    bits: u32,
+}
+
+impl BitBuffer {
+    pub fn new() -> Self {
+        BitBuffer {
+            buf: Vec::new(),
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    pub fn with_capacity(n: usize) -> Self {
+        BitBuffer {
+            buf: Vec::with_capacity(n),
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    /// Pushes the low `width` bits of `value` onto the stream.
+    pub fn push(&mut self, value: u64, width: u32) {
+        self.acc = (self.acc << width) | (value as u128 & ((1u128 << width) - 1));
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bits -= 8;
+            self.buf.push((self.acc >> self.bits) as u8);
+        }
+    }
+
+    /// Pads any trailing partial byte with zero bits and returns the
+    /// packed buffer.
+    pub fn into_inner(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.buf.push(((self.acc << (8 - self.bits)) & 0xFF) as u8);
+        }
+        self.buf
+    }
+}
+
+impl Default for BitBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Packs a `MyStruct` into `sink` at bit granularity: `a` as 64 bits, `b`
+/// as 32, `c` as 8, `d` as a single bit, and `e` as 16.
+pub fn serialize_bits(item: &MyStruct, sink: &mut BitBuffer) {
+    sink.push(item.a, 64);
+    sink.push(item.b as u64, 32);
+    sink.push(item.c as u64, 8);
+    sink.push(item.d as u64, 1);
+    sink.push(item.e as u64, 16);
 }